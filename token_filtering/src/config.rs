@@ -0,0 +1,166 @@
+//! Config-driven thresholds for [`crate::evaluate`]. Every bound that used to
+//! be a compile-time constant lives here so a strategy can be retuned by
+//! editing a TOML/JSON file instead of recompiling.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Every hard-fail/warning bound `evaluate` checks, the rule name/tag lists,
+/// and an optional weighted-scoring mode. Deserializable from a TOML or JSON
+/// file via [`ScreenConfig::load`]; [`ScreenConfig::default`] reproduces the
+/// values this screener originally hardcoded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScreenConfig {
+    // ---- Hard-fail bounds ----
+    pub max_score_normalised: i64,
+    pub max_top_holder_pct: f64,
+    pub min_total_holders: u64,
+    pub max_total_holders: u64,
+    pub max_insiders_pct: f64,
+    pub max_bundler_pct: f64,
+    pub min_bluechip_pct: f64,
+    pub max_fresh_ratio: f64,
+    pub max_bundled_ratio: f64,
+    pub max_gini: f64,
+    pub max_top10_supply_pct: f64,
+    pub max_onchain_top_holder_disagreement: f64,
+
+    // ---- Warning bounds ----
+    pub warn_score_normalised_low: i64,
+    pub warn_score_normalised_high: i64,
+    pub min_total_lp_providers: u64,
+    pub warn_fresh_wallet_count: u64,
+    pub warn_bundler_count: u64,
+    pub warning_count_threshold: usize,
+
+    // ---- Rule lists ----
+    pub critical_risk_names: Vec<String>,
+    pub desirable_holder_tags: Vec<String>,
+
+    // ---- Weighted-score mode ----
+    /// When set, `evaluate` sums each triggered rule's weight into a
+    /// cumulative risk score and fails against `RuleWeights::cutoff` instead
+    /// of using the binary any-hard-fail / warning-count logic.
+    pub weights: Option<RuleWeights>,
+}
+
+impl Default for ScreenConfig {
+    fn default() -> Self {
+        Self {
+            max_score_normalised: 20,
+            max_top_holder_pct: 10.0,
+            min_total_holders: 500,
+            max_total_holders: 3000,
+            max_insiders_pct: 5.0,
+            max_bundler_pct: 30.0,
+            min_bluechip_pct: 0.5,
+            max_fresh_ratio: 0.4,
+            max_bundled_ratio: 0.4,
+            max_gini: 0.85,
+            max_top10_supply_pct: 40.0,
+            max_onchain_top_holder_disagreement: 3.0,
+
+            warn_score_normalised_low: 10,
+            warn_score_normalised_high: 20,
+            min_total_lp_providers: 5,
+            warn_fresh_wallet_count: 100,
+            warn_bundler_count: 100,
+            warning_count_threshold: 2,
+
+            critical_risk_names: [
+                "Top holder concentration",
+                "Creator has rugged",
+                "Creator sold",
+                "Honeypot",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+            desirable_holder_tags: ["bundler", "bluechip", "whale", "axiom"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+
+            weights: None,
+        }
+    }
+}
+
+impl ScreenConfig {
+    /// Loads a config from a `.json` file, or TOML for any other extension
+    /// (including none), matching the repo's TOML-by-default convention.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Ok(serde_json::from_str(&contents)?),
+            _ => Ok(toml::from_str(&contents)?),
+        }
+    }
+}
+
+/// Per-rule weights for the optional weighted-score mode. Each field is the
+/// amount added to the cumulative risk score when that rule triggers;
+/// `cutoff` is the score above which the token fails.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuleWeights {
+    pub score_normalised: f64,
+    pub top_holder_pct: f64,
+    pub total_holders_out_of_range: f64,
+    pub insiders_pct: f64,
+    pub bundler_pct: f64,
+    pub bluechip_pct: f64,
+    pub fresh_ratio: f64,
+    pub bundled_ratio: f64,
+    pub critical_risk: f64,
+    pub rugged: f64,
+    pub gini: f64,
+    pub top10_supply_pct: f64,
+    pub mint_authority_not_revoked: f64,
+    pub freeze_authority_not_revoked: f64,
+    pub onchain_disagreement: f64,
+    pub onchain_check_failed: f64,
+    pub too_clean_top_holders: f64,
+
+    // Per-rule warning weights, mirroring the hard-fail side above.
+    pub warn_score_normalised: f64,
+    pub warn_low_lp_providers: f64,
+    pub warn_risk: f64,
+    pub warn_fresh_wallet_count: f64,
+    pub warn_bundler_count: f64,
+
+    pub cutoff: f64,
+}
+
+impl Default for RuleWeights {
+    fn default() -> Self {
+        Self {
+            score_normalised: 1.0,
+            top_holder_pct: 1.0,
+            total_holders_out_of_range: 1.0,
+            insiders_pct: 1.0,
+            bundler_pct: 1.0,
+            bluechip_pct: 1.0,
+            fresh_ratio: 1.0,
+            bundled_ratio: 1.0,
+            critical_risk: 1.0,
+            rugged: 1.0,
+            gini: 1.0,
+            top10_supply_pct: 1.0,
+            mint_authority_not_revoked: 1.0,
+            freeze_authority_not_revoked: 1.0,
+            onchain_disagreement: 1.0,
+            onchain_check_failed: 1.0,
+            too_clean_top_holders: 1.0,
+
+            warn_score_normalised: 0.5,
+            warn_low_lp_providers: 0.5,
+            warn_risk: 0.5,
+            warn_fresh_wallet_count: 0.5,
+            warn_bundler_count: 0.5,
+
+            cutoff: 1.0,
+        }
+    }
+}
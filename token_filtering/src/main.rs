@@ -1,5 +1,16 @@
+mod config;
+mod onchain;
+mod priority_fee;
+mod watch;
+
+use config::ScreenConfig;
+use onchain::OnchainMintData;
+use priority_fee::PriorityFeeStats;
+use serde::Serialize;
+use solana_client::rpc_client::RpcClient;
 use solana_program::pubkey::Pubkey;
 use std::io::{self, Write};
+use std::time::Duration;
 
 const RUGCHECK_BASE: &str = "https://api.rugcheck.xyz";
 const GMGN_BASE: &str = "https://gmgn.ai";
@@ -50,7 +61,7 @@ fn gmgn_ok(response: &serde_json::Value) -> bool {
 // Struct for screening decision (all data points used by the algo later)
 // -----------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct Risk {
     pub name: Option<String>,
     pub level: Option<String>,
@@ -59,7 +70,7 @@ pub struct Risk {
     pub value: Option<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct TopHolder {
     pub address: String,
     pub pct: f64,
@@ -68,7 +79,7 @@ pub struct TopHolder {
     pub tags: Vec<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct TokenScreenData {
     pub mint: String,
 
@@ -110,102 +121,296 @@ pub struct TokenScreenData {
 
     // GMGN token_holders (top holders with labels)
     pub top_holders: Vec<TopHolder>,
+    pub concentration: Option<ConcentrationStats>,
+
+    // On-chain (authoritative, independent of RugCheck/GMGN)
+    pub mint_authority_revoked: Option<bool>,
+    pub freeze_authority_revoked: Option<bool>,
+    pub onchain_top_holder_pct: Option<f64>,
+    /// Set when the on-chain verification itself couldn't be completed (RPC
+    /// error, rate limit, or an unsupported mint layout e.g. a Token-2022
+    /// mint with extensions).
+    /// Must not be treated as "authorities revoked" - `evaluate` hard-fails
+    /// on this so an unverifiable mint can't silently fall back to trusting
+    /// RugCheck/GMGN alone.
+    pub onchain_check_error: Option<String>,
+
+    // Priority fee recommendation (only computed on a Pass)
+    pub priority_fee: Option<PriorityFeeStats>,
+}
+
+/// Distribution of the pct values of every listed `top_holders` entry (up to
+/// 100, the #1 holder included), to catch concentration that's spread across
+/// a handful of large wallets rather than piled up in a single `top_holder_pct`.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct ConcentrationStats {
+    pub min: f64,
+    pub median: f64,
+    pub p75: f64,
+    pub p90: f64,
+    pub p95: f64,
+    pub max: f64,
+    /// Gini coefficient over the holder pcts (0 = perfectly even, 1 = maximally concentrated).
+    pub gini: f64,
+    /// Cumulative pct held by the top 10 holders in the list.
+    pub top10_supply_pct: f64,
+}
+
+/// Computes [`ConcentrationStats`] from holder pct values, sorting ascending
+/// and indexing at `len*p/100` the same way prioritization-fee trackers derive
+/// percentiles from a sorted fee vector.
+fn compute_concentration_stats(top_holders: &[TopHolder]) -> Option<ConcentrationStats> {
+    if top_holders.is_empty() {
+        return None;
+    }
+
+    // Ascending, mirroring priority_fee.rs, so `pcts[n*p/100]` is actually
+    // the p-th percentile rather than its mirror image.
+    let mut pcts: Vec<f64> = top_holders.iter().map(|h| h.pct).collect();
+    pcts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = pcts.len();
+    let at = |p: usize| pcts[(n * p / 100).min(n - 1)];
+
+    let mean = pcts.iter().sum::<f64>() / n as f64;
+    let gini = if mean > 0.0 {
+        let mut abs_diff_sum = 0.0;
+        for i in 0..n {
+            for j in 0..n {
+                abs_diff_sum += (pcts[i] - pcts[j]).abs();
+            }
+        }
+        abs_diff_sum / (2.0 * (n * n) as f64 * mean)
+    } else {
+        0.0
+    };
+
+    Some(ConcentrationStats {
+        min: pcts[0],
+        median: at(50),
+        p75: at(75),
+        p90: at(90),
+        p95: at(95),
+        max: pcts[n - 1],
+        gini,
+        // Largest holdings are now at the end of the ascending vector.
+        top10_supply_pct: pcts.iter().rev().take(10).sum(),
+    })
+}
+
+#[cfg(test)]
+mod concentration_stats_tests {
+    use super::*;
+
+    fn holder(pct: f64) -> TopHolder {
+        TopHolder {
+            pct,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn p90_is_near_the_top_of_the_distribution_not_the_bottom() {
+        // 10 holders at 1..=10 pct: p90 should land near the concentrated
+        // (high) end, not the long tail of small holders.
+        let holders: Vec<TopHolder> = (1..=10).map(|pct| holder(pct as f64)).collect();
+        let stats = compute_concentration_stats(&holders).unwrap();
+
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 10.0);
+        assert!(
+            stats.p90 > stats.median,
+            "p90 ({}) should exceed the median ({}), not sit near the bottom of the range",
+            stats.p90,
+            stats.median
+        );
+        assert_eq!(stats.top10_supply_pct, 55.0);
+    }
 }
 
 // -----------------------------------------------------------------------------
 // Evaluation: hard fails + warnings → Pass/Fail
 // -----------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ScreenResult {
     Pass,
     Fail,
 }
 
-/// Risk names that mean instant No (hard fail)
-const CRITICAL_RISK_NAMES: &[&str] = &[
-    "Top holder concentration",
-    "Creator has rugged",
-    "Creator sold",
-    "Honeypot",
-];
+/// Compact, scriptable stand-in for the human-readable banners, emitted as a
+/// single JSON object when `--output json` is passed.
+#[derive(Debug, Serialize)]
+struct ScreenOutput<'a> {
+    mint: &'a str,
+    result: ScreenResult,
+    reasons: &'a [String],
+    data: &'a TokenScreenData,
+}
 
-/// Tags that indicate a "diverse" top holder (not "too clean")
-const DESIRABLE_HOLDER_TAGS: &[&str] = &["bundler", "bluechip", "whale", "axiom"];
+fn evaluate(data: &TokenScreenData, config: &ScreenConfig) -> (ScreenResult, Vec<String>) {
+    let weights = config.weights.as_ref();
 
-fn evaluate(data: &TokenScreenData) -> (ScreenResult, Vec<String>) {
-    let mut reasons = Vec::new();
+    // ---- Hard-fail rules ----
+    let mut hard_reasons = Vec::new();
+    let mut hard_score = 0.0;
+    let mut push_hard = |msg: String, weight: f64| {
+        hard_reasons.push(msg);
+        hard_score += weight;
+    };
 
-    // ---- Hard fails ----
     if let Some(score) = data.score_normalised {
-        if score > 20 {
-            reasons.push(format!("HARD: score_normalised {} > 20", score));
+        if score > config.max_score_normalised {
+            push_hard(
+                format!("HARD: score_normalised {} > {}", score, config.max_score_normalised),
+                weights.map(|w| w.score_normalised).unwrap_or_default(),
+            );
         }
     }
 
     if let Some(pct) = data.top_holder_pct {
-        if pct > 10.0 {
-            reasons.push(format!("HARD: top_holder_pct {:.1}% > 10%", pct));
+        if pct > config.max_top_holder_pct {
+            push_hard(
+                format!("HARD: top_holder_pct {:.1}% > {:.1}%", pct, config.max_top_holder_pct),
+                weights.map(|w| w.top_holder_pct).unwrap_or_default(),
+            );
         }
     }
 
-    if data.total_holders < 500 {
-        reasons.push(format!(
-            "HARD: total_holders {} < 500 (too few for new coin)",
-            data.total_holders
-        ));
+    if data.total_holders < config.min_total_holders {
+        push_hard(
+            format!(
+                "HARD: total_holders {} < {} (too few for new coin)",
+                data.total_holders, config.min_total_holders
+            ),
+            weights.map(|w| w.total_holders_out_of_range).unwrap_or_default(),
+        );
     }
-    if data.total_holders > 3000 {
-        reasons.push(format!(
-            "HARD: total_holders {} > 3000 (outside target range)",
-            data.total_holders
-        ));
+    if data.total_holders > config.max_total_holders {
+        push_hard(
+            format!(
+                "HARD: total_holders {} > {} (outside target range)",
+                data.total_holders, config.max_total_holders
+            ),
+            weights.map(|w| w.total_holders_out_of_range).unwrap_or_default(),
+        );
     }
 
     if let Some(pct) = data.insiders_pct {
-        if pct > 5.0 {
-            reasons.push(format!("HARD: insiders_pct {:.1}% > 5%", pct));
+        if pct > config.max_insiders_pct {
+            push_hard(
+                format!("HARD: insiders_pct {:.1}% > {:.1}%", pct, config.max_insiders_pct),
+                weights.map(|w| w.insiders_pct).unwrap_or_default(),
+            );
         }
     }
 
     // Prefer bundler_supply_pct (from token_holders) - more reliable when holder_stat counts exceed total_holders
     let bundler_pct = data.bundler_supply_pct.or(data.bundler_pct);
     if let Some(pct) = bundler_pct {
-        if pct > 30.0 {
-            reasons.push(format!("HARD: bundler_pct {:.1}% > 30%", pct));
+        if pct > config.max_bundler_pct {
+            push_hard(
+                format!("HARD: bundler_pct {:.1}% > {:.1}%", pct, config.max_bundler_pct),
+                weights.map(|w| w.bundler_pct).unwrap_or_default(),
+            );
         }
     }
 
     if let Some(pct) = data.bluechip_pct {
-        if pct < 0.5 {
-            reasons.push(format!("HARD: bluechip_pct {:.2}% < 0.5%", pct));
+        if pct < config.min_bluechip_pct {
+            push_hard(
+                format!("HARD: bluechip_pct {:.2}% < {:.2}%", pct, config.min_bluechip_pct),
+                weights.map(|w| w.bluechip_pct).unwrap_or_default(),
+            );
         }
     }
 
     if let Some(r) = data.fresh_ratio {
-        if r > 0.4 {
-            reasons.push(format!("HARD: fresh_ratio {:.2} > 0.4", r));
+        if r > config.max_fresh_ratio {
+            push_hard(
+                format!("HARD: fresh_ratio {:.2} > {:.2}", r, config.max_fresh_ratio),
+                weights.map(|w| w.fresh_ratio).unwrap_or_default(),
+            );
         }
     }
 
     // Prefer bundler_holder_ratio (from token_holders) - more reliable when holder_stat counts exceed total_holders
     let bundled_ratio = data.bundler_holder_ratio.or(data.bundled_ratio);
     if let Some(r) = bundled_ratio {
-        if r > 0.4 {
-            reasons.push(format!("HARD: bundled_ratio {:.2} > 0.4", r));
+        if r > config.max_bundled_ratio {
+            push_hard(
+                format!("HARD: bundled_ratio {:.2} > {:.2}", r, config.max_bundled_ratio),
+                weights.map(|w| w.bundled_ratio).unwrap_or_default(),
+            );
         }
     }
 
     for risk in &data.risks {
         if let Some(ref name) = risk.name {
-            if CRITICAL_RISK_NAMES.iter().any(|c| name.contains(c)) {
-                reasons.push(format!("HARD: critical risk '{}'", name));
+            if config.critical_risk_names.iter().any(|c| name.contains(c.as_str())) {
+                push_hard(
+                    format!("HARD: critical risk '{}'", name),
+                    weights.map(|w| w.critical_risk).unwrap_or_default(),
+                );
             }
         }
     }
 
     if data.rugged == Some(true) {
-        reasons.push("HARD: token marked as rugged".to_string());
+        push_hard(
+            "HARD: token marked as rugged".to_string(),
+            weights.map(|w| w.rugged).unwrap_or_default(),
+        );
+    }
+
+    if let Some(stats) = data.concentration {
+        if stats.gini > config.max_gini {
+            push_hard(
+                format!("HARD: holder gini {:.2} > {:.2}", stats.gini, config.max_gini),
+                weights.map(|w| w.gini).unwrap_or_default(),
+            );
+        }
+        if stats.top10_supply_pct > config.max_top10_supply_pct {
+            push_hard(
+                format!(
+                    "HARD: top10_supply_pct {:.1}% > {:.1}%",
+                    stats.top10_supply_pct, config.max_top10_supply_pct
+                ),
+                weights.map(|w| w.top10_supply_pct).unwrap_or_default(),
+            );
+        }
+    }
+
+    if let Some(err) = &data.onchain_check_error {
+        push_hard(
+            format!("HARD: on-chain verification failed/unsupported: {}", err),
+            weights.map(|w| w.onchain_check_failed).unwrap_or_default(),
+        );
+    }
+
+    if data.mint_authority_revoked == Some(false) {
+        push_hard(
+            "HARD: mint authority not revoked on-chain".to_string(),
+            weights.map(|w| w.mint_authority_not_revoked).unwrap_or_default(),
+        );
+    }
+    if data.freeze_authority_revoked == Some(false) {
+        push_hard(
+            "HARD: freeze authority not revoked on-chain".to_string(),
+            weights.map(|w| w.freeze_authority_not_revoked).unwrap_or_default(),
+        );
+    }
+    if let (Some(api_pct), Some(onchain_pct)) = (data.top_holder_pct, data.onchain_top_holder_pct) {
+        let diff = (api_pct - onchain_pct).abs();
+        if diff > config.max_onchain_top_holder_disagreement {
+            push_hard(
+                format!(
+                    "HARD: onchain top_holder_pct {:.1}% disagrees with API {:.1}% (Δ{:.1} > {:.1})",
+                    onchain_pct, api_pct, diff, config.max_onchain_top_holder_disagreement
+                ),
+                weights.map(|w| w.onchain_disagreement).unwrap_or_default(),
+            );
+        }
     }
 
     // "Too clean" top holders: top 10 all have only top_holder, none have bundler/bluechip/whale/axiom
@@ -214,70 +419,170 @@ fn evaluate(data: &TokenScreenData) -> (ScreenResult, Vec<String>) {
         h.maker_token_tags
             .iter()
             .chain(h.tags.iter())
-            .any(|t| DESIRABLE_HOLDER_TAGS.iter().any(|d| t.contains(d)))
+            .any(|t| config.desirable_holder_tags.iter().any(|d| t.contains(d.as_str())))
     });
     if !data.top_holders.is_empty() && !has_desirable {
-        reasons.push("HARD: top holders 'too clean' (no bundler/bluechip/whale/axiom)".to_string());
-    }
-
-    if !reasons.is_empty() {
-        return (ScreenResult::Fail, reasons);
+        push_hard(
+            format!(
+                "HARD: top holders 'too clean' (no {})",
+                config.desirable_holder_tags.join("/")
+            ),
+            weights.map(|w| w.too_clean_top_holders).unwrap_or_default(),
+        );
     }
 
     // ---- Warnings ----
-    let mut warn_count = 0;
+    let mut warn_reasons = Vec::new();
+    let mut warn_score = 0.0;
+    let mut push_warn = |msg: String, weight: f64| {
+        warn_reasons.push(msg);
+        warn_score += weight;
+    };
 
     if let Some(score) = data.score_normalised {
-        if (10..=20).contains(&score) {
-            reasons.push(format!("WARN: score_normalised {} in 10-20 (elevated)", score));
-            warn_count += 1;
+        if (config.warn_score_normalised_low..=config.warn_score_normalised_high).contains(&score) {
+            push_warn(
+                format!(
+                    "WARN: score_normalised {} in {}-{} (elevated)",
+                    score, config.warn_score_normalised_low, config.warn_score_normalised_high
+                ),
+                weights.map(|w| w.warn_score_normalised).unwrap_or_default(),
+            );
         }
     }
 
-    if data.total_lp_providers < 5 {
-        reasons.push(format!(
-            "WARN: total_lp_providers {} < 5",
-            data.total_lp_providers
-        ));
-        warn_count += 1;
+    if data.total_lp_providers < config.min_total_lp_providers {
+        push_warn(
+            format!(
+                "WARN: total_lp_providers {} < {}",
+                data.total_lp_providers, config.min_total_lp_providers
+            ),
+            weights.map(|w| w.warn_low_lp_providers).unwrap_or_default(),
+        );
     }
 
     for risk in &data.risks {
         if let Some(ref name) = risk.name {
-            if !CRITICAL_RISK_NAMES.iter().any(|c| name.contains(c)) {
-                reasons.push(format!("WARN: risk '{}'", name));
-                warn_count += 1;
+            if !config.critical_risk_names.iter().any(|c| name.contains(c.as_str())) {
+                push_warn(
+                    format!("WARN: risk '{}'", name),
+                    weights.map(|w| w.warn_risk).unwrap_or_default(),
+                );
             }
         }
     }
 
     if let Some(c) = data.fresh_wallet_count {
-        if c < 100 {
-            reasons.push(format!("WARN: fresh_wallet_count {} < 100", c));
-            warn_count += 1;
+        if c < config.warn_fresh_wallet_count {
+            push_warn(
+                format!("WARN: fresh_wallet_count {} < {}", c, config.warn_fresh_wallet_count),
+                weights.map(|w| w.warn_fresh_wallet_count).unwrap_or_default(),
+            );
         }
     }
 
     if let Some(c) = data.bundler_count {
-        if c < 100 {
-            reasons.push(format!("WARN: bundler_count {} < 100", c));
-            warn_count += 1;
+        if c < config.warn_bundler_count {
+            push_warn(
+                format!("WARN: bundler_count {} < {}", c, config.warn_bundler_count),
+                weights.map(|w| w.warn_bundler_count).unwrap_or_default(),
+            );
         }
     }
 
-    const WARNING_THRESHOLD: usize = 2;
-    if warn_count >= WARNING_THRESHOLD {
+    // ---- Decision ----
+    if let Some(weights) = weights {
+        let score = hard_score + warn_score;
+        let mut reasons = hard_reasons;
+        reasons.extend(warn_reasons);
+        reasons.insert(0, format!("SCORE: {:.2} (cutoff {:.2})", score, weights.cutoff));
+        let result = if score > weights.cutoff {
+            ScreenResult::Fail
+        } else {
+            ScreenResult::Pass
+        };
+        return (result, reasons);
+    }
+
+    if !hard_reasons.is_empty() {
+        return (ScreenResult::Fail, hard_reasons);
+    }
+
+    if warn_reasons.len() >= config.warning_count_threshold {
+        let mut reasons = warn_reasons;
         reasons.insert(
             0,
             format!(
                 "FAIL: {} warnings (threshold {})",
-                warn_count, WARNING_THRESHOLD
+                reasons.len(),
+                config.warning_count_threshold
             ),
         );
         return (ScreenResult::Fail, reasons);
     }
 
-    (ScreenResult::Pass, reasons)
+    (ScreenResult::Pass, warn_reasons)
+}
+
+#[cfg(test)]
+mod evaluate_tests {
+    use super::*;
+    use config::RuleWeights;
+
+    /// A baseline that trips no hard-fail rule and only the
+    /// `total_lp_providers` warning (default `total_lp_providers: 0`).
+    fn base_data() -> TokenScreenData {
+        TokenScreenData {
+            total_holders: 1000,
+            top_holder_pct: Some(5.0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unweighted_pass_when_no_hard_fails_and_few_warnings() {
+        let (result, reasons) = evaluate(&base_data(), &ScreenConfig::default());
+        assert_eq!(result, ScreenResult::Pass);
+        assert!(reasons.iter().any(|r| r.contains("total_lp_providers")));
+    }
+
+    #[test]
+    fn unweighted_fails_on_a_single_hard_rule() {
+        let data = TokenScreenData {
+            total_holders: 100, // below config.min_total_holders (500)
+            ..base_data()
+        };
+        let (result, reasons) = evaluate(&data, &ScreenConfig::default());
+        assert_eq!(result, ScreenResult::Fail);
+        assert!(reasons.iter().any(|r| r.contains("total_holders")));
+    }
+
+    #[test]
+    fn weighted_mode_sums_each_rule_own_weight_against_cutoff() {
+        let config = ScreenConfig {
+            weights: Some(RuleWeights {
+                total_holders_out_of_range: 1.0,
+                warn_low_lp_providers: 0.5,
+                cutoff: 1.4,
+                ..RuleWeights::default()
+            }),
+            ..ScreenConfig::default()
+        };
+
+        // Only the 0.5-weighted warning triggers: 0.5 <= 1.4 cutoff -> Pass.
+        let (result, reasons) = evaluate(&base_data(), &config);
+        assert_eq!(result, ScreenResult::Pass);
+        assert!(reasons[0].starts_with("SCORE: 0.50"));
+
+        // Adding the 1.0-weighted hard rule pushes the sum to 1.5 > 1.4.
+        let data = TokenScreenData {
+            total_holders: 100,
+            ..base_data()
+        };
+        let (result, reasons) = evaluate(&data, &config);
+        assert_eq!(result, ScreenResult::Fail);
+        assert!(reasons[0].starts_with("SCORE: 1.50"));
+    }
 }
 
 fn parse_risk(v: &serde_json::Value) -> Risk {
@@ -436,27 +741,157 @@ fn merge_gmgn_token_holders(data: &mut TokenScreenData, json: &serde_json::Value
     } else {
         None
     };
+
+    data.concentration = compute_concentration_stats(&data.top_holders);
 }
 
-#[tokio::main]
-async fn main() {
-    let mut user_input = String::new();
-    print!("please enter token address: ");
-    io::stdout().flush().unwrap();
-    io::stdin()
-        .read_line(&mut user_input)
-        .expect("failed to read user input");
+fn merge_onchain_data(data: &mut TokenScreenData, onchain: OnchainMintData) {
+    data.mint_authority_revoked = Some(onchain.mint_authority_revoked);
+    data.freeze_authority_revoked = Some(onchain.freeze_authority_revoked);
+    data.onchain_top_holder_pct = onchain.onchain_top_holder_pct;
+}
 
-    let mint = user_input.trim();
-    let _token_address: Pubkey = mint.parse().expect("invalid pubkey");
+/// Output mode for the final verdict, mirroring Solana ledger-tool's
+/// `--output json` convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputMode {
+    Text,
+    Json,
+}
+
+/// Parses `--output <mode>` out of the process args. Defaults to `Text`.
+fn parse_output_mode() -> OutputMode {
+    let args: Vec<String> = std::env::args().collect();
+    for (i, arg) in args.iter().enumerate() {
+        if arg == "--output" {
+            if args.get(i + 1).map(String::as_str) == Some("json") {
+                return OutputMode::Json;
+            }
+        } else if let Some(mode) = arg.strip_prefix("--output=") {
+            if mode == "json" {
+                return OutputMode::Json;
+            }
+        }
+    }
+    OutputMode::Text
+}
+
+/// Parses `--rpc-url <url>` out of the process args, falling back to the
+/// `RPC_URL` env var and then [`onchain::DEFAULT_RPC_URL`].
+fn parse_rpc_url() -> String {
+    let args: Vec<String> = std::env::args().collect();
+    for (i, arg) in args.iter().enumerate() {
+        if arg == "--rpc-url" {
+            if let Some(url) = args.get(i + 1) {
+                return url.clone();
+            }
+        } else if let Some(url) = arg.strip_prefix("--rpc-url=") {
+            return url.to_string();
+        }
+    }
+    std::env::var("RPC_URL").unwrap_or_else(|_| onchain::DEFAULT_RPC_URL.to_string())
+}
 
-    println!("starting rug pull check for {}", mint);
+/// Parses `--pool-address <address>` (or the `POOL_ADDRESS` env var) out of
+/// the process args. The LP transaction writes to the pool as well as the
+/// mint, so the priority-fee recommendation should sample both when a pool
+/// is known; without one it falls back to the mint alone.
+fn parse_pool_address() -> Option<Pubkey> {
+    let args: Vec<String> = std::env::args().collect();
+    let raw = args.iter().enumerate().find_map(|(i, arg)| {
+        if arg == "--pool-address" {
+            args.get(i + 1).cloned()
+        } else {
+            arg.strip_prefix("--pool-address=").map(String::from)
+        }
+    });
+    let raw = raw.or_else(|| std::env::var("POOL_ADDRESS").ok())?;
+    Some(
+        raw.parse()
+            .unwrap_or_else(|e| panic!("invalid --pool-address '{}': {}", raw, e)),
+    )
+}
+
+/// Parses `--config <path>` (TOML or JSON, see [`ScreenConfig::load`]) out of
+/// the process args, falling back to [`ScreenConfig::default`] when unset.
+fn parse_screen_config() -> ScreenConfig {
+    let args: Vec<String> = std::env::args().collect();
+    let path = args.iter().enumerate().find_map(|(i, arg)| {
+        if arg == "--config" {
+            args.get(i + 1).cloned()
+        } else {
+            arg.strip_prefix("--config=").map(String::from)
+        }
+    });
+
+    match path {
+        Some(path) => ScreenConfig::load(std::path::Path::new(&path))
+            .unwrap_or_else(|e| panic!("failed to load screen config from {}: {}", path, e)),
+        None => ScreenConfig::default(),
+    }
+}
+
+/// Default polling period for `--watch` when no interval is given.
+const DEFAULT_WATCH_INTERVAL_SECS: u64 = 30;
+
+/// Parses `--watch` (and an optional `--watch-interval-secs <n>`) into a
+/// [`watch::WatchTrigger`]. Defaults to polling every
+/// [`DEFAULT_WATCH_INTERVAL_SECS`] - the risk signals this feature exists to
+/// catch (a top holder dumping, bundler activity) live in separate SPL token
+/// accounts, not the mint account itself, so subscribing to the mint alone
+/// would in practice never fire. `--watch-subscribe-mint` opts into that
+/// mint-account subscription instead, for the rare case it's actually useful
+/// (e.g. watching for a freeze/mint authority change).
+fn parse_watch_trigger(rpc_url: &str) -> Option<watch::WatchTrigger> {
+    let args: Vec<String> = std::env::args().collect();
+    if !args.iter().any(|a| a == "--watch") {
+        return None;
+    }
+
+    for (i, arg) in args.iter().enumerate() {
+        let secs = if arg == "--watch-interval-secs" {
+            args.get(i + 1).and_then(|s| s.parse::<u64>().ok())
+        } else {
+            arg.strip_prefix("--watch-interval-secs=")
+                .and_then(|s| s.parse::<u64>().ok())
+        };
+        if let Some(secs) = secs {
+            return Some(watch::WatchTrigger::Poll(Duration::from_secs(secs)));
+        }
+    }
+
+    if args.iter().any(|a| a == "--watch-subscribe-mint") {
+        let pubsub_url = std::env::var("PUBSUB_URL").unwrap_or_else(|_| {
+            rpc_url
+                .replacen("https://", "wss://", 1)
+                .replacen("http://", "ws://", 1)
+        });
+        return Some(watch::WatchTrigger::AccountChange { pubsub_url });
+    }
+
+    Some(watch::WatchTrigger::Poll(Duration::from_secs(DEFAULT_WATCH_INTERVAL_SECS)))
+}
 
+/// Runs the full fetch+evaluate pipeline once for `mint`.
+async fn run_screen(
+    mint: &str,
+    token_address: Pubkey,
+    pool_address: Option<Pubkey>,
+    rpc_url: &str,
+    config: &ScreenConfig,
+) -> (TokenScreenData, ScreenResult, Vec<String>) {
     let rugcheck = match get_rugcheck_report(mint).await {
         Ok(r) => r,
         Err(e) => {
-            eprintln!("failed to fetch RugCheck report: {}", e);
-            return;
+            let data = TokenScreenData {
+                mint: mint.to_string(),
+                ..Default::default()
+            };
+            return (
+                data,
+                ScreenResult::Fail,
+                vec![format!("ERROR: failed to fetch RugCheck report: {}", e)],
+            );
         }
     };
 
@@ -474,23 +909,139 @@ async fn main() {
         }
     }
 
+    let onchain_rpc_url = rpc_url.to_string();
+    let onchain_result = tokio::task::spawn_blocking(move || {
+        let rpc_client = RpcClient::new(onchain_rpc_url);
+        onchain::fetch_onchain_mint_data(&rpc_client, &token_address)
+    })
+    .await
+    .expect("on-chain verification task panicked");
+
+    match onchain_result {
+        Ok(onchain_data) => merge_onchain_data(&mut data, onchain_data),
+        Err(e) => {
+            eprintln!("failed to verify mint on-chain: {}", e);
+            data.onchain_check_error = Some(e.to_string());
+        }
+    }
+
+    let (result, reasons) = evaluate(&data, config);
+
+    if result == ScreenResult::Pass {
+        // Writable accounts for the LP transaction: the mint, plus the pool
+        // when the caller passed one via `--pool-address`/`POOL_ADDRESS`.
+        let mut writable_accounts = vec![token_address];
+        writable_accounts.extend(pool_address);
+        let fee_rpc_url = rpc_url.to_string();
+        let fee_result = tokio::task::spawn_blocking(move || {
+            let rpc_client = RpcClient::new(fee_rpc_url);
+            priority_fee::fetch_priority_fee_stats(&rpc_client, &writable_accounts)
+        })
+        .await
+        .expect("priority fee task panicked");
+
+        match fee_result {
+            Ok(stats) => data.priority_fee = stats,
+            Err(e) => eprintln!("failed to fetch priority fee recommendation: {}", e),
+        }
+    }
+
+    (data, result, reasons)
+}
+
+fn print_screen_result(
+    mint: &str,
+    output_mode: OutputMode,
+    data: &TokenScreenData,
+    result: ScreenResult,
+    reasons: &[String],
+) {
+    if output_mode == OutputMode::Json {
+        let output = ScreenOutput {
+            mint,
+            result,
+            reasons,
+            data,
+        };
+        println!("{}", serde_json::to_string(&output).expect("failed to serialize screen output"));
+        return;
+    }
+
     println!("\n========== TOKEN SCREEN DATA ==========");
     println!("{:#?}", data);
 
-    let (result, reasons) = evaluate(&data);
     println!("\n========== RESULT ==========");
     match result {
         ScreenResult::Pass => {
             println!("Worth providing liquidity: Yes");
-            for r in &reasons {
+            for r in reasons {
                 println!("  (warning) {}", r);
             }
+            if let Some(stats) = data.priority_fee {
+                println!(
+                    "  recommended compute-unit price: {} micro-lamports/CU (p90)",
+                    stats.recommended_micro_lamports_per_cu()
+                );
+            }
         }
         ScreenResult::Fail => {
             println!("Worth providing liquidity: No");
-            for r in &reasons {
+            for r in reasons {
                 println!("  - {}", r);
             }
         }
     }
 }
+
+#[tokio::main]
+async fn main() {
+    let output_mode = parse_output_mode();
+    let rpc_url = parse_rpc_url();
+    let watch_trigger = parse_watch_trigger(&rpc_url);
+    let config = parse_screen_config();
+    let pool_address = parse_pool_address();
+
+    let mut user_input = String::new();
+    if output_mode == OutputMode::Text {
+        print!("please enter token address: ");
+        io::stdout().flush().unwrap();
+    }
+    io::stdin()
+        .read_line(&mut user_input)
+        .expect("failed to read user input");
+
+    let mint = user_input.trim();
+    let token_address: Pubkey = mint.parse().expect("invalid pubkey");
+
+    if output_mode == OutputMode::Text {
+        println!("starting rug pull check for {}", mint);
+    }
+
+    let Some(watch_trigger) = watch_trigger else {
+        let (data, result, reasons) = run_screen(mint, token_address, pool_address, &rpc_url, &config).await;
+        print_screen_result(mint, output_mode, &data, result, &reasons);
+        return;
+    };
+
+    // Watch mode: keep re-screening and only emit a record when the verdict
+    // or a key metric moves, rather than flooding stdout on every tick.
+    let mut prev_snapshot: Option<watch::WatchSnapshot> = None;
+    loop {
+        let (data, result, reasons) = run_screen(mint, token_address, pool_address, &rpc_url, &config).await;
+        let snapshot = watch::WatchSnapshot::from_screen(result, &data);
+
+        let should_emit = prev_snapshot
+            .as_ref()
+            .map(|prev| snapshot.differs_materially(prev))
+            .unwrap_or(true);
+        if should_emit {
+            print_screen_result(mint, output_mode, &data, result, &reasons);
+        }
+        prev_snapshot = Some(snapshot);
+
+        if let Err(e) = watch::wait_for_next_tick(&watch_trigger, token_address).await {
+            eprintln!("watch trigger error: {}", e);
+            break;
+        }
+    }
+}
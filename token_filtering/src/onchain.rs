@@ -0,0 +1,57 @@
+//! Direct on-chain verification of mint/freeze authority and top-holder
+//! concentration, so a spoofed or stale RugCheck/GMGN response can't pass a
+//! token whose authorities were never actually revoked.
+//!
+//! [`Mint::unpack`] only accepts the legacy 82-byte SPL Token mint layout, so
+//! a Token-2022 mint carrying extensions (or any RPC failure) surfaces as an
+//! `Err` here.
+//! Callers must treat that as "couldn't verify", not "fine" -
+//! `TokenScreenData::onchain_check_error` exists for exactly this.
+
+use serde::Serialize;
+use solana_client::rpc_client::RpcClient;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use spl_token::state::Mint;
+
+/// Authoritative, RPC-derived facts about a mint that the API-based screen
+/// can't be trusted to report correctly.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct OnchainMintData {
+    pub mint_authority_revoked: bool,
+    pub freeze_authority_revoked: bool,
+    pub onchain_top_holder_pct: Option<f64>,
+}
+
+/// Default RPC endpoint when neither `--rpc-url` nor `RPC_URL` is set.
+pub const DEFAULT_RPC_URL: &str = "https://api.mainnet-beta.solana.com";
+
+/// Fetches and deserializes the SPL Token mint account, and independently
+/// recomputes the top-holder pct from `getTokenLargestAccounts` /
+/// `getTokenSupply`, instead of trusting RugCheck/GMGN's numbers.
+pub fn fetch_onchain_mint_data(
+    client: &RpcClient,
+    mint: &Pubkey,
+) -> Result<OnchainMintData, Box<dyn std::error::Error + Send + Sync>> {
+    let account_data = client.get_account_data(mint)?;
+    let mint_state = Mint::unpack(&account_data)?;
+
+    let supply = client.get_token_supply(mint)?;
+    let supply_amount = supply.ui_amount.unwrap_or(0.0);
+
+    let onchain_top_holder_pct = if supply_amount > 0.0 {
+        client
+            .get_token_largest_accounts(mint)?
+            .first()
+            .and_then(|largest| largest.amount.ui_amount)
+            .map(|top| (top / supply_amount) * 100.0)
+    } else {
+        None
+    };
+
+    Ok(OnchainMintData {
+        mint_authority_revoked: mint_state.mint_authority.is_none(),
+        freeze_authority_revoked: mint_state.freeze_authority.is_none(),
+        onchain_top_holder_pct,
+    })
+}
@@ -0,0 +1,104 @@
+//! Priority-fee recommendation for landing the liquidity-provision
+//! transaction, computed the same way prioritization-fee sidecars derive
+//! percentiles from a recent per-CU fee sample.
+
+use serde::Serialize;
+use solana_client::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+
+/// Percentile summary of recent per-compute-unit prioritization fees
+/// (micro-lamports per CU).
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct PriorityFeeStats {
+    pub min: u64,
+    pub median: u64,
+    pub p75: u64,
+    pub p90: u64,
+    pub p95: u64,
+    pub max: u64,
+}
+
+impl PriorityFeeStats {
+    /// The compute-unit price to set on the LP transaction so it lands
+    /// reliably: the p90 of recent per-CU fees on the relevant accounts.
+    pub fn recommended_micro_lamports_per_cu(&self) -> u64 {
+        self.p90
+    }
+}
+
+/// Calls `getRecentPrioritizationFees` for the given writable accounts (the
+/// mint and pool) and summarizes the per-CU fee distribution via
+/// [`summarize_priority_fees`].
+pub fn fetch_priority_fee_stats(
+    client: &RpcClient,
+    writable_accounts: &[Pubkey],
+) -> Result<Option<PriorityFeeStats>, Box<dyn std::error::Error + Send + Sync>> {
+    let fees: Vec<u64> = client
+        .get_recent_prioritization_fees(writable_accounts)?
+        .iter()
+        .map(|f| f.prioritization_fee)
+        .collect();
+
+    Ok(summarize_priority_fees(fees))
+}
+
+/// Computes [`PriorityFeeStats`] from a raw per-CU fee sample, sorting
+/// ascending and indexing at `len*p/100` the same way
+/// `compute_concentration_stats` derives percentiles from holder pcts,
+/// guarding the empty/one-element cases.
+fn summarize_priority_fees(mut fees: Vec<u64>) -> Option<PriorityFeeStats> {
+    if fees.is_empty() {
+        return None;
+    }
+    fees.sort_unstable();
+
+    let n = fees.len();
+    let at = |p: usize| fees[(n * p / 100).min(n - 1)];
+
+    Some(PriorityFeeStats {
+        min: fees[0],
+        median: at(50),
+        p75: at(75),
+        p90: at(90),
+        p95: at(95),
+        max: fees[n - 1],
+    })
+}
+
+#[cfg(test)]
+mod priority_fee_stats_tests {
+    use super::*;
+
+    #[test]
+    fn empty_sample_yields_no_stats() {
+        assert!(summarize_priority_fees(vec![]).is_none());
+    }
+
+    #[test]
+    fn single_fee_is_every_percentile() {
+        let stats = summarize_priority_fees(vec![42]).unwrap();
+        assert_eq!(stats.min, 42);
+        assert_eq!(stats.median, 42);
+        assert_eq!(stats.p75, 42);
+        assert_eq!(stats.p90, 42);
+        assert_eq!(stats.p95, 42);
+        assert_eq!(stats.max, 42);
+    }
+
+    #[test]
+    fn p90_is_near_the_top_of_the_distribution_not_the_bottom() {
+        // 10 fees at 1..=10, shuffled, so a sort-direction bug (as in
+        // 22ffd36) would be caught rather than masked by input order.
+        let fees: Vec<u64> = vec![7, 2, 10, 4, 1, 9, 3, 6, 8, 5];
+        let stats = summarize_priority_fees(fees).unwrap();
+
+        assert_eq!(stats.min, 1);
+        assert_eq!(stats.max, 10);
+        assert!(
+            stats.p90 > stats.median,
+            "p90 ({}) should exceed the median ({}), not sit near the bottom of the range",
+            stats.p90,
+            stats.median
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Re-screens a mint on a subscription interval, so a liquidity provider can
+//! keep watching a position after entering and bail out when the risk
+//! profile deteriorates. Modeled on Solana's `PubsubClient` account/slot
+//! subscription pattern: a tick is either a fixed wall-clock period or an
+//! on-chain account change, rather than a fixed sleep alone.
+//!
+//! The risk signals this is meant to catch - a top holder dumping, bundler
+//! activity, concentration shifting - live in the holders' own token
+//! accounts, not in the mint account's data (supply/authorities), which
+//! rarely changes after creation. So `AccountChange` is opt-in for watching
+//! the mint specifically (e.g. an authority change); the default trigger is
+//! `Poll`.
+
+use crate::{ScreenResult, TokenScreenData};
+use serde::Serialize;
+use solana_client::pubsub_client::PubsubClient;
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_program::pubkey::Pubkey;
+use std::time::Duration;
+
+/// What triggers each re-screen in watch mode.
+pub enum WatchTrigger {
+    /// Re-screen on a fixed wall-clock period.
+    Poll(Duration),
+    /// Re-screen whenever the mint account changes on-chain. Opt-in only:
+    /// the mint account itself rarely changes after creation, so relying on
+    /// this alone would in practice never re-trigger.
+    AccountChange { pubsub_url: String },
+}
+
+/// Pct-point change in `top_holder_pct` between ticks above which a record is
+/// worth reporting on its own, even if the verdict didn't flip.
+const TOP_HOLDER_PCT_JUMP_THRESHOLD: f64 = 2.0;
+
+/// The subset of a screen result that matters for detecting a material
+/// change between watch ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct WatchSnapshot {
+    pub result: ScreenResult,
+    pub top_holder_pct: Option<f64>,
+    pub rugged: Option<bool>,
+}
+
+impl WatchSnapshot {
+    pub fn from_screen(result: ScreenResult, data: &TokenScreenData) -> Self {
+        Self {
+            result,
+            top_holder_pct: data.top_holder_pct,
+            rugged: data.rugged,
+        }
+    }
+
+    /// True if `self` differs from `prev` in a way worth emitting a record
+    /// for: the verdict flipped, the top holder pct jumped, or the token
+    /// newly got marked as rugged.
+    pub fn differs_materially(&self, prev: &WatchSnapshot) -> bool {
+        if self.result != prev.result {
+            return true;
+        }
+        if self.rugged == Some(true) && prev.rugged != Some(true) {
+            return true;
+        }
+        match (self.top_holder_pct, prev.top_holder_pct) {
+            (Some(now), Some(before)) => (now - before).abs() > TOP_HOLDER_PCT_JUMP_THRESHOLD,
+            _ => false,
+        }
+    }
+}
+
+/// Blocks until the next re-screen should happen: sleeps for `Poll`, or
+/// waits on the mint's account-change subscription for `AccountChange`.
+pub async fn wait_for_next_tick(
+    trigger: &WatchTrigger,
+    mint: Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match trigger {
+        WatchTrigger::Poll(period) => {
+            tokio::time::sleep(*period).await;
+            Ok(())
+        }
+        WatchTrigger::AccountChange { pubsub_url } => {
+            let pubsub_url = pubsub_url.clone();
+            tokio::task::spawn_blocking(move || -> Result<(), String> {
+                let (_subscription, receiver) = PubsubClient::account_subscribe(
+                    &pubsub_url,
+                    &mint,
+                    Some(RpcAccountInfoConfig::default()),
+                )
+                .map_err(|e| e.to_string())?;
+                receiver.recv().map_err(|e| e.to_string())?;
+                Ok(())
+            })
+            .await
+            .expect("account subscription task panicked")
+            .map_err(|e| e.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod watch_snapshot_tests {
+    use super::*;
+
+    fn snapshot(result: ScreenResult, top_holder_pct: Option<f64>, rugged: Option<bool>) -> WatchSnapshot {
+        WatchSnapshot {
+            result,
+            top_holder_pct,
+            rugged,
+        }
+    }
+
+    #[test]
+    fn same_result_and_small_pct_delta_does_not_emit() {
+        let prev = snapshot(ScreenResult::Pass, Some(5.0), Some(false));
+        let now = snapshot(ScreenResult::Pass, Some(5.5), Some(false));
+        assert!(!now.differs_materially(&prev));
+    }
+
+    #[test]
+    fn pass_to_fail_flip_emits() {
+        let prev = snapshot(ScreenResult::Pass, Some(5.0), Some(false));
+        let now = snapshot(ScreenResult::Fail, Some(5.0), Some(false));
+        assert!(now.differs_materially(&prev));
+    }
+
+    #[test]
+    fn rugged_flipping_true_emits() {
+        let prev = snapshot(ScreenResult::Pass, Some(5.0), Some(false));
+        let now = snapshot(ScreenResult::Pass, Some(5.0), Some(true));
+        assert!(now.differs_materially(&prev));
+    }
+
+    #[test]
+    fn pct_jump_above_threshold_with_same_result_emits() {
+        let prev = snapshot(ScreenResult::Pass, Some(5.0), Some(false));
+        let now = snapshot(ScreenResult::Pass, Some(7.5), Some(false));
+        assert!(now.differs_materially(&prev));
+    }
+}